@@ -740,6 +740,7 @@ static bool handle_typed_command(char *data, uint16_t len) {
             uint8_t payload[4];
             payload[0] = NOTIFY_PROTO_VER;   /* 2 = typed-command capable (firmware-owned, §4.6) */
             payload[1] = NOTIFY_FEATURE_APPLY_HOST_CONTEXT
+                       | NOTIFY_FEATURE_ABORT_FRAME                    /* CAN abort frame always honored */
                        | (get_host_callbacks_size() > 0 ? NOTIFY_FEATURE_CALLBACK_REGISTRY : 0);
             payload[2] = (uint8_t)get_host_callbacks_size();          /* 0 when no DEFINE_HOST_CALLBACKS */
             payload[3] = board_rules_present() ? 1 : 0;               /* single bit (§4.6) */
@@ -848,10 +849,44 @@ static bool handle_typed_command(char *data, uint16_t len) {
 
 void hid_notify(uint8_t *data, uint8_t length) {
     // Check for our identifiers to ensure no conflicts with other libraries
-    if (length < 2 || data[0] != 0x81 || data[1] != 0x9F) {
+    if (length < 2 || data[0] != NOTIFIER_MAGIC_BYTE_0 || data[1] != NOTIFIER_MAGIC_BYTE_1) {
         return; // Discard the message if it doesn't match
     }
 
+    /* ABORT FRAME (§4.6): a report whose payload is exactly CAN (0x18) followed
+     * by zero padding tells the firmware the host gave up on the message in
+     * flight (a batch write failed partway through). Discard the partial
+     * buffer and every reassembly flag so the next message starts clean
+     * instead of being spliced onto the leftover bytes while the firmware
+     * waits for an ETX that will never come. Requiring the all-zero tail keeps
+     * the frame unambiguous: a legacy continuation report may legitimately
+     * begin with a raw 0x18 (the sanitizer only strips it later, at ETX), but
+     * it then carries payload and/or an ETX and is reassembled as usual. The
+     * frame is honored even while a typed command still owes literal argument
+     * bytes: a real APPLY_HOST_CONTEXT ids tail is followed by more ids or by
+     * ETX, never by an all-zero remainder, so this is the only way to cancel
+     * a multi-report typed message without leaving bytes in msg_buffer.
+     * Replies with a CAN echo ([0x18][padding]), distinct from the legacy 0/1
+     * match-bool and the 0x51 typed marker, so the host can confirm the abort.
+     * Advertised via NOTIFY_FEATURE_ABORT_FRAME. */
+    if (length >= 3 && data[2] == CAN_ABORT[0]) {
+        bool abort_frame = true;
+        for (uint8_t i = 3; i < length; i++) {
+            if (data[i] != 0) { abort_frame = false; break; }
+        }
+        if (abort_frame) {
+            msg_index = 0;
+            dropping = false;
+            typed_mode = false;
+            typed_literal_remaining = 0;
+            typed_awaiting_terminator = false;
+            uint8_t response[RAW_REPORT_SIZE] = {0};
+            response[0] = (uint8_t)CAN_ABORT[0];   /* CAN echo: abort applied */
+            raw_hid_send(response, RAW_REPORT_SIZE);
+            return;
+        }
+    }
+
     /* §4.6 typed-command discriminator: data[2] == 0xF0 routes to the typed
      * path (handle_typed_command), which bypasses process_full_message so it
      * has NO board disable/deactivate side effects. Checked ONLY on the first
@@ -33,8 +33,19 @@ void notifier_set_os(os_variant_t os);
 host_callback_t* get_host_callbacks(void);
 size_t           get_host_callbacks_size(void);
 
+// Magic header prefixed to every report (coexistence guard). Override both in
+// config.h for a firmware fork that uses a different prefix; the host's prefix
+// bytes must match or every report is ignored.
+#ifndef NOTIFIER_MAGIC_BYTE_0
+#define NOTIFIER_MAGIC_BYTE_0 0x81
+#endif
+#ifndef NOTIFIER_MAGIC_BYTE_1
+#define NOTIFIER_MAGIC_BYTE_1 0x9F
+#endif
+
 #define GS_DELIMITER "\x1D"  // ASCII 29 (Group Separator)
 #define ETX_TERMINATOR "\x03"  // ASCII 3 (End of Text)
+#define CAN_ABORT "\x18"  // ASCII 24 (Cancel) — abort frame [CAN][0x00…] (§4.6)
 #define WINDOW_TITLE(classname, title) classname GS_DELIMITER title
 #define WT(...) WINDOW_TITLE(__VA_ARGS__)
 
@@ -52,10 +63,11 @@ size_t           get_host_callbacks_size(void);
 // Protocol version: 1 = legacy string-only; 2 = typed-command capable (§4.6).
 #define NOTIFY_PROTO_VER              2      // §4.6
 // feature_flags BIT positions (§4.6); notifier.c builds the mask at runtime:
-//   0x01 | (get_host_callbacks_size()>0 ? 0x02 : 0)
+//   0x01 | 0x08 | (get_host_callbacks_size()>0 ? 0x02 : 0)
 #define NOTIFY_FEATURE_APPLY_HOST_CONTEXT 0x01  // §4.6
 #define NOTIFY_FEATURE_CALLBACK_REGISTRY  0x02  // §4.6
 #define NOTIFY_FEATURE_VIA_COEXIST        0x04  // §4.6 (reserved)
+#define NOTIFY_FEATURE_ABORT_FRAME        0x08  // §4.6 (CAN abort frame)
 // Host-callback registry cap (§14) — bounds host_cb_enabled[] in notifier.c.
 #define HOST_CALLBACK_MAX              32     // §14
 // Host layers reserved >= 224 so they resolve above board layers (§14/§16;
@@ -11,7 +11,9 @@
 # APPLY_HOST_CONTEXT, coexistence, and multi-report framing) can be validated
 # with plain gcc on a host. It builds THREE drivers — test_notifier_dispatch,
 # test_notifier_os, and test_notifier_host — from a SINGLE stub-compiled
-# notifier.o (PRD §11.1, §11.2D). See PRP P2 / P1.M2.T2 / P1.M3.T1.
+# notifier.o (PRD §11.1, §11.2D). See PRP P2 / P1.M2.T2 / P1.M3.T1. A fourth
+# driver, test_notifier_magic, links a second notifier.o built with an
+# overridden NOTIFIER_MAGIC_BYTE_0/1 (the config.h override path).
 set -u
 cd "$(dirname "$0")"
 
@@ -19,33 +21,49 @@ OBJ=/tmp/notifier_stub.o
 DRV=/tmp/test_notifier_dispatch
 OST=/tmp/test_notifier_os
 HST=/tmp/test_notifier_host
+OBJ_M=/tmp/notifier_stub_magic.o
+MAG=/tmp/test_notifier_magic
 
-echo "[1/5] stub-compile notifier.c (shared by all three test binaries) ..."
+echo "[1/7] stub-compile notifier.c (shared by the first three test binaries) ..."
 gcc -Wall -Wextra -std=c99 \
     -DQMK_KEYBOARD_H='"qmk_keyboard_stub.h"' \
     -Iqmk_stubs -I. \
     -c notifier.c -o "$OBJ"
 if [ $? -ne 0 ]; then echo "COMPILE FAILED"; exit 2; fi
 
-echo "[2/5] link dispatch driver (test_notifier_dispatch) ..."
+echo "[2/7] link dispatch driver (test_notifier_dispatch) ..."
 gcc -Wall -std=c99 -Iqmk_stubs -I. \
     "$OBJ" qmk_stubs/qmk_stubs.c test_notifier_dispatch.c \
     -o "$DRV"
 if [ $? -ne 0 ]; then echo "LINK FAILED (dispatch)"; rm -f "$OBJ"; exit 3; fi
 
-echo "[3/5] link multi-OS driver (test_notifier_os) ..."
+echo "[3/7] link multi-OS driver (test_notifier_os) ..."
 gcc -Wall -std=c99 -Iqmk_stubs -I. \
     "$OBJ" qmk_stubs/qmk_stubs.c test_notifier_os.c \
     -o "$OST"
 if [ $? -ne 0 ]; then echo "LINK FAILED (os)"; rm -f "$OBJ" "$DRV"; exit 4; fi
 
-echo "[4/5] link host driver (test_notifier_host) ..."
+echo "[4/7] link host driver (test_notifier_host) ..."
 gcc -Wall -std=c99 -Iqmk_stubs -I. \
     "$OBJ" qmk_stubs/qmk_stubs.c test_notifier_host.c \
     -o "$HST"
 if [ $? -ne 0 ]; then echo "LINK FAILED (host)"; rm -f "$OBJ" "$DRV" "$OST"; exit 5; fi
 
-echo "[5/5] run all three ..."
+echo "[5/7] stub-compile notifier.c with an overridden magic header ..."
+gcc -Wall -Wextra -std=c99 \
+    -DQMK_KEYBOARD_H='"qmk_keyboard_stub.h"' \
+    -DNOTIFIER_MAGIC_BYTE_0=0x82 -DNOTIFIER_MAGIC_BYTE_1=0x9E \
+    -Iqmk_stubs -I. \
+    -c notifier.c -o "$OBJ_M"
+if [ $? -ne 0 ]; then echo "COMPILE FAILED (magic)"; rm -f "$OBJ" "$DRV" "$OST" "$HST"; exit 6; fi
+
+echo "[6/7] link magic-header driver (test_notifier_magic) ..."
+gcc -Wall -std=c99 -Iqmk_stubs -I. \
+    "$OBJ_M" qmk_stubs/qmk_stubs.c test_notifier_magic.c \
+    -o "$MAG"
+if [ $? -ne 0 ]; then echo "LINK FAILED (magic)"; rm -f "$OBJ" "$DRV" "$OST" "$HST" "$OBJ_M"; exit 7; fi
+
+echo "[7/7] run all four ..."
 "$DRV"
 rc_d=$?
 fails_d=$("$DRV" 2>/dev/null | grep -c '^FAIL:' || true)
@@ -55,14 +73,19 @@ fails_o=$("$OST" 2>/dev/null | grep -c '^FAIL:' || true)
 "$HST"
 rc_h=$?
 fails_h=$("$HST" 2>/dev/null | grep -c '^FAIL:' || true)
+"$MAG"
+rc_m=$?
+fails_m=$("$MAG" 2>/dev/null | grep -c '^FAIL:' || true)
 echo "------------------------------------------------"
 echo "notifier dispatch fails=$fails_d  (exit=$rc_d)"
 echo "notifier os fails=$fails_o  (exit=$rc_o)"
 echo "notifier host fails=$fails_h  (exit=$rc_h)"
-rm -f "$OBJ" "$DRV" "$OST" "$HST"
+echo "notifier magic fails=$fails_m  (exit=$rc_m)"
+rm -f "$OBJ" "$DRV" "$OST" "$HST" "$OBJ_M" "$MAG"
 if [ "$fails_d" -eq 0 ] && [ $rc_d -eq 0 ] \
    && [ "$fails_o" -eq 0 ] && [ $rc_o -eq 0 ] \
-   && [ "$fails_h" -eq 0 ] && [ $rc_h -eq 0 ]; then
+   && [ "$fails_h" -eq 0 ] && [ $rc_h -eq 0 ] \
+   && [ "$fails_m" -eq 0 ] && [ $rc_m -eq 0 ]; then
     echo "✓ notifier stub-compile gate PASSED"
     exit 0
 fi
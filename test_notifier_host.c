@@ -24,6 +24,13 @@
  * section (adv-A..adv-D): malformed/truncated/abandoned AHC must not
  * permanently break legacy routing (and typed recovers after a flush).
  *
+ * The ABORT section (abort-A..abort-G) gates the CAN (0x18) abort frame: a
+ * report whose payload is exactly CAN + zero padding discards the partial
+ * message in flight — legacy or typed, including a multi-report AHC that
+ * still owes ids (abort-G) — and replies with a CAN echo. A report that
+ * merely BEGINS with 0x18 but carries further payload is not an abort and
+ * still reassembles (abort-E typed, abort-F legacy).
+ *
  * Build (PRD §11.1):
  *   gcc -DQMK_KEYBOARD_H='"qmk_keyboard_stub.h"' -Iqmk_stubs -I. \
  *       notifier.c qmk_stubs/qmk_stubs.c test_notifier_host.c -std=c99
@@ -646,6 +653,138 @@ int main(void) {
         CK(stub_get_active_layer() == 5,    "(adv-H) legacy dispatch activated board layer 5 after abandon [Issue 1/§12]");
     }
 
+    /* ================================================================ */
+    /* ===== ABORT FRAME — CAN (0x18) discards a partial message ======== */
+    /* ================================================================ */
+    /* A host whose batch write fails partway through a multi-report message
+     * sends [0x81][0x9F][0x18] so the firmware drops the partial buffer
+     * instead of splicing it onto the next message. */
+
+    /* ===== (abort-A) QUERY_INFO advertises the abort frame ===== */
+    {
+        const uint8_t *r = send_typed(NOTIFY_CMD_QUERY_INFO, NULL, 0);
+        CK(r[0] == NOTIFY_RESPONSE_MARKER,          "(abort-A) QUERY_INFO r[0]=0x51 marker [§4.6]");
+        CK(r[3] & NOTIFY_FEATURE_ABORT_FRAME,       "(abort-A) QUERY_INFO feature_flags bit 0x08 (abort frame) set");
+    }
+
+    /* ===== (abort-B) partial legacy message discarded — no splice =====
+     * "neov" (no ETX), abort, then "ide"+ETX. Without the abort the buffer
+     * would reassemble to "neovide" and match; with it only "ide" dispatches. */
+    {
+        board_cmd_en = board_cmd_dis = 0;
+        uint8_t p[32]; memset(p, 0, sizeof(p));
+        p[0]=0x81; p[1]=0x9F; p[2]='n'; p[3]='e'; p[4]='o'; p[5]='v';
+        hid_notify(p, 6);                           /* partial, NO ETX */
+        uint8_t a[32]; memset(a, 0, sizeof(a));
+        a[0]=0x81; a[1]=0x9F; a[2]=CAN_ABORT[0];
+        hid_notify(a, 32);
+        const uint8_t *ra = stub_get_last_response();
+        CK(ra[0] == CAN_ABORT[0],                   "(abort-B) abort frame replies CAN echo r[0]=0x18 (not 0/1/0x51)");
+        uint8_t t[32]; memset(t, 0, sizeof(t));
+        t[0]=0x81; t[1]=0x9F; t[2]='i'; t[3]='d'; t[4]='e'; t[5]=ETX_TERMINATOR[0];
+        hid_notify(t, 32);
+        const uint8_t *rt = stub_get_last_response();
+        CK(rt[0] == 0,                              "(abort-B) post-abort 'ide' NOT spliced onto 'neov' (ack 0)");
+        CK(board_cmd_en == 0,                       "(abort-B) post-abort board command NOT enabled");
+    }
+
+    /* ===== (abort-C) legacy routing resumes cleanly after an abort ===== */
+    {
+        board_cmd_en = board_cmd_dis = 0;
+        uint8_t a[32]; memset(a, 0, sizeof(a));
+        a[0]=0x81; a[1]=0x9F; a[2]=CAN_ABORT[0];
+        hid_notify(a, 32);                          /* abort with nothing in flight: no-op */
+        const uint8_t *ra = stub_get_last_response();
+        CK(ra[0] == CAN_ABORT[0],                   "(abort-C) idle abort frame still replies CAN echo r[0]=0x18");
+        uint8_t s[32]; memset(s, 0, sizeof(s));
+        s[0]=0x81; s[1]=0x9F;
+        s[2]='n'; s[3]='e'; s[4]='o'; s[5]='v'; s[6]='i'; s[7]='d'; s[8]='e';
+        s[9]=ETX_TERMINATOR[0];
+        hid_notify(s, 32);
+        const uint8_t *rs = stub_get_last_response();
+        CK(rs[0] == 1,                              "(abort-C) legacy 'neovide' dispatches after abort (ack 1)");
+        CK(board_cmd_en == 1,                       "(abort-C) legacy dispatch fired board on_enable after abort");
+        CK(stub_get_active_layer() == 5,            "(abort-C) legacy dispatch activated board layer 5 after abort");
+    }
+
+    /* ===== (abort-D) abandoned typed frame — typed recovers WITHOUT a flush =====
+     * adv-C/adv-D need a legacy ETX flush before the next typed command is
+     * recognized; an abort frame resets msg_index directly. */
+    {
+        uint8_t r[32]; memset(r, 0, sizeof(r));
+        r[0]=0x81; r[1]=0x9F; r[2]=NOTIFY_CMD_DISCRIMINATOR; r[3]=NOTIFY_CMD_APPLY_HOST_CONTEXT;
+        r[4]=234; r[5]=0x00; r[6]=0;                /* count=0, NO ETX — abandoned */
+        hid_notify(r, 32);
+        uint8_t a[32]; memset(a, 0, sizeof(a));
+        a[0]=0x81; a[1]=0x9F; a[2]=CAN_ABORT[0];
+        hid_notify(a, 32);
+        const uint8_t *rq = send_typed(NOTIFY_CMD_QUERY_INFO, NULL, 0);
+        CK(rq[0] == NOTIFY_RESPONSE_MARKER,         "(abort-D) QUERY_INFO right after abort r[0]=0x51 (typed recovered)");
+        CK(rq[1] == NOTIFY_CMD_QUERY_INFO,          "(abort-D) QUERY_INFO right after abort r[1]=0x01 echo [§4.6]");
+    }
+
+    /* ===== (abort-E) 0x18-led typed continuation is payload, NOT an abort =====
+     * Two-report AHC (count=28) whose continuation report begins with id 0x18
+     * (24 — out of registry range, so inert) and ends in ETX. Its tail is not
+     * all-zero, so it is not an abort frame and the ids reassemble. */
+    {
+        uint8_t rep1[32]; memset(rep1, 0, sizeof(rep1));
+        rep1[0] = 0x81; rep1[1] = 0x9F; rep1[2] = NOTIFY_CMD_DISCRIMINATOR;
+        rep1[3] = NOTIFY_CMD_APPLY_HOST_CONTEXT;
+        rep1[4] = 225; rep1[5] = 0x00; rep1[6] = 28;   /* 25 ids here, 3 in report 2 */
+        hid_notify(rep1, 32);
+        uint8_t rep2[32]; memset(rep2, 0, sizeof(rep2));
+        rep2[0] = 0x81; rep2[1] = 0x9F;
+        rep2[2] = CAN_ABORT[0];                        /* id25 == 0x18, owed literally */
+        rep2[5] = ETX_TERMINATOR[0];
+        hid_notify(rep2, 32);
+        const uint8_t *r = stub_get_last_response();
+        CK(r[0] == NOTIFY_RESPONSE_MARKER,          "(abort-E) typed literal 0x18 NOT treated as abort: r[0]=0x51");
+        CK(r[1] == NOTIFY_CMD_APPLY_HOST_CONTEXT,   "(abort-E) two-report AHC r[1]=0x05 echo (reassembly OK) [§4.6]");
+        CK(stub_get_active_layer() == 225,          "(abort-E) two-report AHC host layer 225 active");
+    }
+
+    /* ===== (abort-F) legacy continuation starting with raw 0x18 is NOT an abort =====
+     * The sanitizer strips 0x18 only at ETX, so a legacy host may deliver it
+     * raw at data[2] of a continuation report. That report carries payload and
+     * an ETX, so it is not [CAN][0x00…] and must reassemble: "neov" + 0x18 +
+     * "ide" sanitizes to "neovide" and dispatches. */
+    {
+        board_cmd_en = board_cmd_dis = 0;
+        uint8_t p[32]; memset(p, 0, sizeof(p));
+        p[0]=0x81; p[1]=0x9F; p[2]='n'; p[3]='e'; p[4]='o'; p[5]='v';
+        hid_notify(p, 6);                           /* partial, NO ETX */
+        uint8_t c[32]; memset(c, 0, sizeof(c));
+        c[0]=0x81; c[1]=0x9F; c[2]=CAN_ABORT[0];    /* raw 0x18 leads the continuation */
+        c[3]='i'; c[4]='d'; c[5]='e'; c[6]=ETX_TERMINATOR[0];
+        hid_notify(c, 32);
+        const uint8_t *rc = stub_get_last_response();
+        CK(rc[0] == 1,                              "(abort-F) 0x18-led legacy continuation reassembles 'neovide' (ack 1)");
+        CK(board_cmd_en == 1,                       "(abort-F) 0x18-led legacy continuation fired board on_enable");
+    }
+
+    /* ===== (abort-G) abort a multi-report AHC that still owes ids =====
+     * The case the abort frame exists for: report 1 of a count=28 AHC lands,
+     * the write of report 2 fails, and the host sends an abort frame instead.
+     * The abort must be honored (CAN echo) and leave msg_buffer empty, so the
+     * very next typed QUERY_INFO is recognized on the first try. */
+    {
+        uint8_t rep1[32]; memset(rep1, 0, sizeof(rep1));
+        rep1[0] = 0x81; rep1[1] = 0x9F; rep1[2] = NOTIFY_CMD_DISCRIMINATOR;
+        rep1[3] = NOTIFY_CMD_APPLY_HOST_CONTEXT;
+        rep1[4] = 226; rep1[5] = 0x00; rep1[6] = 28;   /* 25 ids here, 3 still owed */
+        hid_notify(rep1, 32);
+        uint8_t a[32]; memset(a, 0, sizeof(a));
+        a[0]=0x81; a[1]=0x9F; a[2]=CAN_ABORT[0];
+        hid_notify(a, 32);
+        const uint8_t *ra = stub_get_last_response();
+        CK(ra[0] == CAN_ABORT[0],                   "(abort-G) abort mid-AHC honored: CAN echo r[0]=0x18");
+        CK(stub_get_active_layer() != 226,          "(abort-G) aborted AHC NOT applied (host layer 226 inactive)");
+        const uint8_t *rq = send_typed(NOTIFY_CMD_QUERY_INFO, NULL, 0);
+        CK(rq[0] == NOTIFY_RESPONSE_MARKER,         "(abort-G) first QUERY_INFO after abort r[0]=0x51 (no padding leak)");
+        CK(rq[1] == NOTIFY_CMD_QUERY_INFO,          "(abort-G) first QUERY_INFO after abort r[1]=0x01 echo [§4.6]");
+    }
+
     printf("\nTotal tests run: %d / passed: %d / failed: %d\n", g_pass + g_fail, g_pass, g_fail);
     return g_fail ? 1 : 0;
 }
\ No newline at end of file
@@ -0,0 +1,55 @@
+/* test_notifier_magic.c — overridden magic header host test.
+ *
+ * Linked against a SECOND stub-compiled notifier.o built with
+ * -DNOTIFIER_MAGIC_BYTE_0=0x82 -DNOTIFIER_MAGIC_BYTE_1=0x9E (see
+ * run_notifier_stub_tests.sh), i.e. what a keymap's config.h override yields.
+ * Asserts hid_notify honors the overridden prefix and ignores the default
+ * 0x81 0x9F one. Follows the test_notifier_dispatch.c / test_notifier_os.c
+ * pattern (file-scope DEFINE_*, CK helper, PASS:/FAIL:, summary,
+ * return g_fail?1:0); the runner greps `grep -c '^FAIL:'`.
+ */
+#include <stdint.h>
+#include <stdbool.h>
+#include <string.h>
+#include <stdio.h>
+#include "notifier.h"
+
+/* Non-static entry point implemented in notifier.c. */
+void hid_notify(uint8_t *data, uint8_t length);
+
+static int cmd_en = 0;
+static void cmd_on(void)  { cmd_en++; }
+static void cmd_off(void) { }
+DEFINE_SERIAL_COMMANDS({
+    { "neovide", cmd_on, cmd_off, false },
+});
+
+static int g_pass = 0, g_fail = 0;
+#define CK(cond, name) do { \
+    if (cond) { g_pass++; printf("PASS: %s\n", name); } \
+    else      { g_fail++; printf("FAIL: %s\n", name); } \
+} while (0)
+
+/* one-report legacy "neovide"+ETX behind the given 2-byte prefix */
+static void send_neovide(uint8_t m0, uint8_t m1) {
+    uint8_t rep[32]; memset(rep, 0, sizeof(rep));
+    rep[0] = m0; rep[1] = m1;
+    memcpy(rep + 2, "neovide", 7);
+    rep[9] = ETX_TERMINATOR[0];
+    hid_notify(rep, 32);
+}
+
+int main(void) {
+    /* ===== (i) default prefix is ignored once overridden ===== */
+    cmd_en = 0;
+    send_neovide(0x81, 0x9F);
+    CK(cmd_en == 0, "(i) [81 9F] report ignored under overridden magic header");
+
+    /* ===== (ii) overridden prefix dispatches ===== */
+    cmd_en = 0;
+    send_neovide(0x82, 0x9E);
+    CK(cmd_en == 1, "(ii) [82 9E] report reassembled and dispatched");
+
+    printf("\nTotal tests run: %d / passed: %d / failed: %d\n", g_pass + g_fail, g_pass, g_fail);
+    return g_fail ? 1 : 0;
+}